@@ -6,5 +6,5 @@ mod metadata;
 mod sync;
 mod unsync;
 
-pub use sync::ProjectArc;
-pub use unsync::ProjectRc;
+pub use sync::{ProjectArc, ProjectArcBorrow, WeakProjectArc};
+pub use unsync::{ProjectRc, ProjectRcBorrow, WeakProjectRc};
@@ -1,41 +1,72 @@
-pub(crate) trait Droppable {}
-
-impl<T> Droppable for T {}
-
-#[repr(C)]
-struct VTable {
-    drop_in_place: unsafe fn(*mut ()),
-    size_of: usize,
-    align_of: usize,
-}
-
+/// Type- and length-erased description of a payload stored behind an
+/// `RcInner`/`ArcInner` header.
+///
+/// `metadata` carries the pointer metadata needed to reconstitute a fat
+/// pointer to the payload (the slice length, or `0` for sized types), so
+/// that `drop_in_place` can drop the payload without the caller needing to
+/// know its original (possibly projected-away) type.
 #[derive(Copy, Clone)]
 pub(crate) struct TypeMetadata {
-    vtable: &'static VTable,
+    drop_in_place: unsafe fn(*mut (), usize),
+    size_of: usize,
+    align_of: usize,
+    metadata: usize,
 }
 
 impl TypeMetadata {
     pub(crate) fn size_of(&self) -> usize {
-        self.vtable.size_of
+        self.size_of
     }
 
     pub(crate) fn align_of(&self) -> usize {
-        self.vtable.align_of
+        self.align_of
+    }
+}
+
+unsafe fn drop_sized<T>(ptr: *mut (), _metadata: usize) {
+    // SAFETY: forwarded from `drop_in_place`'s caller.
+    unsafe {
+        std::ptr::drop_in_place(ptr as *mut T);
+    }
+}
+
+unsafe fn drop_slice<T>(ptr: *mut (), len: usize) {
+    let slice_ptr = std::ptr::slice_from_raw_parts_mut(ptr as *mut T, len);
+
+    // SAFETY: forwarded from `drop_in_place`'s caller; `len` is the length
+    // this payload was allocated with.
+    unsafe {
+        std::ptr::drop_in_place(slice_ptr);
     }
 }
 
 pub(crate) fn metadata_of<T>() -> TypeMetadata {
-    let ptr = std::ptr::null::<T>() as *const dyn Droppable;
-    let fat = unsafe { std::mem::transmute::<_, [usize; 2]>(ptr) };
-    let vtable = unsafe { &*(fat[1] as *const VTable) };
+    TypeMetadata {
+        drop_in_place: drop_sized::<T>,
+        size_of: std::mem::size_of::<T>(),
+        align_of: std::mem::align_of::<T>(),
+        metadata: 0,
+    }
+}
 
-    TypeMetadata { vtable }
+/// Builds the metadata for a `[T]` payload of the given length.
+///
+/// This doesn't need a `'static` vtable keyed on `len`: `drop_slice::<T>`
+/// is monomorphized once per `T` regardless of length, and the length
+/// itself is carried in `metadata`.
+pub(crate) fn metadata_of_slice<T>(len: usize) -> TypeMetadata {
+    TypeMetadata {
+        drop_in_place: drop_slice::<T>,
+        size_of: len * std::mem::size_of::<T>(),
+        align_of: std::mem::align_of::<T>(),
+        metadata: len,
+    }
 }
 
 pub(crate) unsafe fn drop_in_place(ptr: *mut (), meta: TypeMetadata) {
     // SAFETY:
-    // 1. ptr is non-null
-    // 2. TypeMetadata is the vtable corresponding to ptr's `Droppable` impl
-    // 3. ptr will not be accessed after this
-    (meta.vtable.drop_in_place)(ptr)
+    // 1. ptr is non-null and points at a payload of the shape `meta`
+    //    describes
+    // 2. ptr will not be accessed after this
+    unsafe { (meta.drop_in_place)(ptr, meta.metadata) }
 }
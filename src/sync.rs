@@ -1,38 +1,57 @@
 use std::{
     alloc::{alloc, handle_alloc_error, Layout},
+    marker::PhantomData,
     mem::ManuallyDrop,
     ops::Deref,
     ptr::{null_mut, NonNull},
     sync::atomic::{AtomicUsize, Ordering},
 };
 
-use crate::metadata::{drop_in_place, metadata_of, TypeMetadata};
+use crate::metadata::{drop_in_place, metadata_of, metadata_of_slice, TypeMetadata};
 
 pub struct ProjectArc<T: ?Sized> {
     inner: NonNull<ArcInner>,
     pointer: NonNull<T>,
 }
 
+/// Allocates an `ArcInner` for `meta`, with the strong/weak counts and
+/// metadata already written, leaving only the payload to be initialized.
+///
+/// `initial_strong` is almost always `1`; [`ProjectArc::new_cyclic`] is the
+/// one exception, starting at `0` so that weak upgrades during construction
+/// correctly observe no live strong handles yet.
+fn alloc_inner(meta: TypeMetadata, initial_strong: usize) -> (*mut u8, ArcInnerLayout) {
+    let layout = arc_inner_layout(meta);
+
+    let ptr = unsafe { alloc(layout.layout) };
+
+    if ptr == null_mut() {
+        handle_alloc_error(layout.layout);
+    }
+
+    unsafe {
+        // Write the strong count
+        ptr.add(layout.strong_offset)
+            .cast::<AtomicUsize>()
+            .write(AtomicUsize::new(initial_strong));
+        // Write 1 as the weak count: all outstanding strong handles
+        // collectively own a single unit of weak count.
+        ptr.add(layout.weak_offset)
+            .cast::<AtomicUsize>()
+            .write(AtomicUsize::new(1));
+        // Write the metadata
+        ptr.add(layout.drop_offset).cast::<TypeMetadata>().write(meta);
+    }
+
+    (ptr, layout)
+}
+
 impl<T> ProjectArc<T> {
     pub fn new(thing: T) -> Self {
         let meta = metadata_of::<T>();
-        let layout = arc_inner_layout(meta);
-
-        let ptr = unsafe { alloc(layout.layout) };
-
-        if ptr == null_mut() {
-            handle_alloc_error(layout.layout);
-        }
+        let (ptr, layout) = alloc_inner(meta, 1);
 
         unsafe {
-            // Write 0 as the strong count
-            ptr.add(layout.strong_offset)
-                .cast::<AtomicUsize>()
-                .write(AtomicUsize::new(1));
-            // Write the metadata
-            ptr.add(layout.drop_offset)
-                .cast::<TypeMetadata>()
-                .write(meta);
             // Write the actual pointee
             ptr.add(layout.payload_offset).cast::<T>().write(thing);
 
@@ -45,6 +64,242 @@ impl<T> ProjectArc<T> {
             }
         }
     }
+
+    /// Constructs a `ProjectArc<T>` that can hold a weak pointer back to
+    /// itself, by giving `data_fn` a [`WeakProjectArc<T>`] aimed at the
+    /// (not yet initialized) allocation.
+    ///
+    /// The strong count stays at `0` for the duration of `data_fn`, so any
+    /// `upgrade` attempted on the passed-in weak handle before construction
+    /// finishes correctly observes no live owner and returns `None`. If
+    /// `data_fn` panics, the allocation is freed without ever dropping the
+    /// payload, since it was never initialized.
+    pub fn new_cyclic<F>(data_fn: F) -> Self
+    where
+        F: FnOnce(&WeakProjectArc<T>) -> T,
+    {
+        let meta = metadata_of::<T>();
+        let (ptr, layout) = alloc_inner(meta, 0);
+
+        let inner_ptr = NonNull::new(ptr as *mut ArcInner).unwrap();
+        let payload_ptr =
+            NonNull::new(unsafe { ptr.add(layout.payload_offset) } as *mut T).unwrap();
+
+        // This owns the one unit of weak count we just allocated. If
+        // `data_fn` panics, unwinding drops it here, which frees the
+        // allocation without touching the uninitialized payload.
+        let weak = WeakProjectArc {
+            inner: inner_ptr,
+            pointer: payload_ptr,
+        };
+
+        let value = data_fn(&weak);
+
+        unsafe {
+            payload_ptr.as_ptr().write(value);
+
+            // Hand the weak handle's unit of weak count off to the strong
+            // handles we're about to create, instead of giving it back by
+            // dropping `weak` normally.
+            std::mem::forget(weak);
+            (*inner_ptr.as_ptr()).strong.store(1, Ordering::Release);
+        }
+
+        ProjectArc {
+            inner: inner_ptr,
+            pointer: payload_ptr,
+        }
+    }
+
+    /// Consumes the handle and returns a raw pointer to the payload,
+    /// without decrementing the strong count.
+    ///
+    /// The returned pointer carries the strong count this handle held, which
+    /// must eventually be released by passing it to
+    /// [`from_raw`](ProjectArc::from_raw) (and dropping the result) or to
+    /// [`decrement_strong_count`](ProjectArc::decrement_strong_count), or the
+    /// allocation leaks. Each call to
+    /// [`increment_strong_count`](ProjectArc::increment_strong_count) on it
+    /// adds one more strong count to be released the same way. All three
+    /// recover the header by walking backwards
+    /// from the payload by the same offset `new` used to place it, so `ptr`
+    /// must still point at the start of the live payload: a handle that has
+    /// been [`project`](ProjectArc::project)ed onto a sub-field no longer
+    /// satisfies that and must not be passed through `into_raw`.
+    pub fn into_raw(self) -> *const T {
+        let self_ = ManuallyDrop::new(self);
+        self_.pointer.as_ptr()
+    }
+
+    /// Reconstructs a `ProjectArc<T>` from a pointer previously returned by
+    /// [`ProjectArc::into_raw`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have come from `into_raw` called on a `ProjectArc<T>` of
+    /// this same, unprojected `T`, and must still carry a strong count that
+    /// has not been released yet: `into_raw` and each `increment_strong_count`
+    /// give it one, `from_raw` and each `decrement_strong_count` release one.
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        let layout = arc_inner_layout(metadata_of::<T>());
+        let inner = unsafe { (ptr as *mut u8).sub(layout.payload_offset) } as *mut ArcInner;
+
+        ProjectArc {
+            inner: NonNull::new(inner).unwrap(),
+            pointer: NonNull::new(ptr as *mut T).unwrap(),
+        }
+    }
+
+    /// Increments the strong count of the allocation `ptr` points into,
+    /// without materializing a `ProjectArc<T>`.
+    ///
+    /// # Safety
+    ///
+    /// Same pairing contract as [`ProjectArc::from_raw`]: `ptr` must have
+    /// come from `into_raw` of an unprojected `ProjectArc<T>`, and the
+    /// allocation must still be live.
+    pub unsafe fn increment_strong_count(ptr: *const T) {
+        let arc = ManuallyDrop::new(unsafe { ProjectArc::from_raw(ptr) });
+        // Cloning bumps the strong count; forgetting the clone (and never
+        // dropping `arc` itself) leaves `ptr` accounting for exactly the one
+        // unit of ownership it already represented, plus the new one just
+        // created.
+        std::mem::forget((*arc).clone());
+    }
+
+    /// Decrements the strong count of the allocation `ptr` points into,
+    /// dropping the payload and/or deallocating if this was the last
+    /// strong (or weak) handle.
+    ///
+    /// # Safety
+    ///
+    /// Same pairing contract as [`ProjectArc::from_raw`]: `ptr` must have
+    /// come from `into_raw` of an unprojected `ProjectArc<T>`, and must still
+    /// carry a strong count that has not been released yet.
+    pub unsafe fn decrement_strong_count(ptr: *const T) {
+        drop(unsafe { ProjectArc::from_raw(ptr) });
+    }
+}
+
+impl<T: Clone> ProjectArc<[T]> {
+    /// Builds a `ProjectArc<[T]>` by cloning every element out of `slice`
+    /// into a single allocation holding the header and the payload inline.
+    pub fn from_slice(slice: &[T]) -> Self {
+        let len = slice.len();
+        let meta = metadata_of_slice::<T>(len);
+        let (ptr, layout) = alloc_inner(meta, 1);
+
+        unsafe {
+            let payload_base = ptr.add(layout.payload_offset) as *mut T;
+
+            // `item.clone()` can panic partway through; this guard drops
+            // the elements already written and frees the allocation on
+            // unwind, the same way std builds `Rc<[T]>`/`Arc<[T]>` from a
+            // slice.
+            let mut guard = PartialSliceWrite {
+                ptr,
+                layout: layout.layout,
+                payload_base,
+                written: 0,
+            };
+            for item in slice {
+                payload_base.add(guard.written).write(item.clone());
+                guard.written += 1;
+            }
+            std::mem::forget(guard);
+
+            let inner_ptr = NonNull::new(ptr as *mut ArcInner).unwrap();
+            let pointer =
+                NonNull::new(std::ptr::slice_from_raw_parts_mut(payload_base, len)).unwrap();
+
+            ProjectArc {
+                inner: inner_ptr,
+                pointer,
+            }
+        }
+    }
+}
+
+/// Drops the already-written prefix of a partially-initialized slice
+/// payload and frees the backing allocation, for unwinding out of a
+/// panicking per-element write (e.g. [`ProjectArc::from_slice`]'s
+/// `item.clone()`).
+struct PartialSliceWrite<T> {
+    ptr: *mut u8,
+    layout: Layout,
+    payload_base: *mut T,
+    written: usize,
+}
+
+impl<T> Drop for PartialSliceWrite<T> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in 0..self.written {
+                self.payload_base.add(i).drop_in_place();
+            }
+            std::alloc::dealloc(self.ptr, self.layout);
+        }
+    }
+}
+
+impl<T> ProjectArc<[T]> {
+    /// Builds a `ProjectArc<[T]>` from an iterator, without needing to know
+    /// the length up front.
+    ///
+    /// Named `collect_slice` rather than `from_iter` so it doesn't collide
+    /// with (and shadow) `std::iter::FromIterator::from_iter`.
+    pub fn collect_slice<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        let len = items.len();
+        let meta = metadata_of_slice::<T>(len);
+        let (ptr, layout) = alloc_inner(meta, 1);
+
+        unsafe {
+            let payload_base = ptr.add(layout.payload_offset) as *mut T;
+
+            for (i, item) in items.into_iter().enumerate() {
+                payload_base.add(i).write(item);
+            }
+
+            let inner_ptr = NonNull::new(ptr as *mut ArcInner).unwrap();
+            let pointer =
+                NonNull::new(std::ptr::slice_from_raw_parts_mut(payload_base, len)).unwrap();
+
+            ProjectArc {
+                inner: inner_ptr,
+                pointer,
+            }
+        }
+    }
+}
+
+impl ProjectArc<str> {
+    /// Builds a `ProjectArc<str>` by copying `s`'s bytes into a single
+    /// allocation holding the header and the payload inline.
+    ///
+    /// Named `copy_str` rather than `from_str` so it doesn't collide with
+    /// (and shadow) `std::str::FromStr::from_str`.
+    pub fn copy_str(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let len = bytes.len();
+        let meta = metadata_of_slice::<u8>(len);
+        let (ptr, layout) = alloc_inner(meta, 1);
+
+        unsafe {
+            let payload_base = ptr.add(layout.payload_offset);
+            payload_base.copy_from_nonoverlapping(bytes.as_ptr(), len);
+
+            let inner_ptr = NonNull::new(ptr as *mut ArcInner).unwrap();
+            // `bytes` came from a valid `&str`, so the copy is valid UTF-8.
+            let byte_slice = std::slice::from_raw_parts_mut(payload_base, len);
+            let pointer = NonNull::from(std::str::from_utf8_unchecked_mut(byte_slice));
+
+            ProjectArc {
+                inner: inner_ptr,
+                pointer,
+            }
+        }
+    }
 }
 
 impl<T: ?Sized> ProjectArc<T> {
@@ -75,6 +330,148 @@ impl<T: ?Sized> ProjectArc<T> {
     {
         self.clone().project(f)
     }
+
+    /// Coerces the projected value into an unsized `U` (for example,
+    /// `dyn Trait`), on stable.
+    ///
+    /// This is just [`project`](ProjectArc::project) under a name that makes
+    /// the "turn this concrete type into a trait object" case
+    /// discoverable without the nightly `unsize` feature: the ordinary
+    /// reference-unsizing coercion (`&T` to `&dyn Trait`) is stable, so `f`
+    /// can build `&U` by simply returning its argument under an explicit
+    /// unsized return type.
+    pub fn project_unsize<F, U: ?Sized>(self, f: F) -> ProjectArc<U>
+    where
+        F: for<'a> FnOnce(&'a T) -> &'a U,
+    {
+        self.project(f)
+    }
+}
+
+impl<T, const N: usize> ProjectArc<[T; N]> {
+    /// Converts a `ProjectArc` over a fixed-size array into one over a
+    /// slice, on stable, by projecting onto the array's `as_slice()`.
+    pub fn unsize_array(self) -> ProjectArc<[T]> {
+        self.project(|array| array.as_slice())
+    }
+}
+
+impl<T: ?Sized> ProjectArc<T> {
+    /// Returns the number of strong (`ProjectArc`) handles to this allocation.
+    pub fn strong_count(&self) -> usize {
+        self.inner().strong.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of [`WeakProjectArc`] handles to this allocation.
+    pub fn weak_count(&self) -> usize {
+        let weak = self.inner().weak.load(Ordering::Acquire);
+
+        if weak == usize::MAX {
+            // `is_unique` holds `weak` locked, which it only does while
+            // there are no weak handles.
+            0
+        } else {
+            // One unit of weak count is owned collectively by the strong
+            // handles, so it isn't counted here.
+            weak - 1
+        }
+    }
+
+    /// Creates a new [`WeakProjectArc`] pointer to this allocation.
+    pub fn downgrade(&self) -> WeakProjectArc<T> {
+        let mut weak = self.inner().weak.load(Ordering::Relaxed);
+
+        loop {
+            // `usize::MAX` means `is_unique` holds `weak` locked; wait for
+            // it to be released rather than wrapping the count.
+            if weak == usize::MAX {
+                std::hint::spin_loop();
+                weak = self.inner().weak.load(Ordering::Relaxed);
+                continue;
+            }
+
+            match self.inner().weak.compare_exchange_weak(
+                weak,
+                weak + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => weak = actual,
+            }
+        }
+
+        WeakProjectArc {
+            inner: self.inner,
+            pointer: self.pointer,
+        }
+    }
+
+    /// Returns true if this is the only strong handle and there are no
+    /// outstanding [`WeakProjectArc`] handles either.
+    ///
+    /// Loading `strong` and `weak` as two independent atomics would be
+    /// racy: a concurrent `downgrade`+`upgrade`+`drop(weak)` round-trip on
+    /// another handle could restore `weak` to 1 in between our two loads,
+    /// making a stale `strong == 1` look uncontended when another strong
+    /// handle has since been created. So, as std's `Arc::is_unique` does,
+    /// lock `weak` at 1 first with a compare-exchange: any concurrent
+    /// `downgrade` bumps `weak` away from 1, which makes the
+    /// compare-exchange fail here and correctly reports non-unique.
+    fn is_unique(&self) -> bool {
+        if self
+            .inner()
+            .weak
+            .compare_exchange(1, usize::MAX, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+        {
+            let unique = self.inner().strong.load(Ordering::Acquire) == 1;
+            // Release the lock.
+            self.inner().weak.store(1, Ordering::Release);
+            unique
+        } else {
+            false
+        }
+    }
+
+    /// Returns a mutable reference to the projected value, but only if this
+    /// is the only handle (strong or weak) to the allocation.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.is_unique() {
+            Some(unsafe { &mut *self.pointer.as_ptr() })
+        } else {
+            None
+        }
+    }
+
+    /// Borrows this handle without touching the strong count.
+    ///
+    /// The resulting [`ProjectArcBorrow`] is a plain `Copy` value tied to the
+    /// lifetime of `self`, useful for passing through call chains that only
+    /// need read access without paying for a clone/drop pair at every step.
+    pub fn borrow(&self) -> ProjectArcBorrow<'_, T> {
+        ProjectArcBorrow {
+            inner: self.inner,
+            pointer: self.pointer,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> ProjectArc<T> {
+    /// Returns a mutable reference to the value, cloning the allocation
+    /// first if this handle is not the sole owner.
+    ///
+    /// Only the value this handle points at is cloned: on a handle projected
+    /// down to a sub-field, the new allocation holds just that sub-field,
+    /// not the full original value.
+    pub fn make_mut(&mut self) -> &mut T {
+        if !self.is_unique() {
+            *self = ProjectArc::new((**self).clone());
+        }
+
+        unsafe { &mut *self.pointer.as_ptr() }
+    }
 }
 
 unsafe impl<T> Send for ProjectArc<T> where T: Send + Sync + ?Sized {}
@@ -106,7 +503,17 @@ impl<T: ?Sized> Drop for ProjectArc<T> {
 
         if count == 1 {
             unsafe {
-                deallocate(self.inner);
+                drop_payload(self.inner);
+            }
+
+            // The strong handles no longer exist, so they give up their
+            // shared unit of weak count.
+            let weak = self.inner().weak.fetch_sub(1, Ordering::AcqRel);
+
+            if weak == 1 {
+                unsafe {
+                    deallocate(self.inner);
+                }
             }
         }
     }
@@ -114,12 +521,165 @@ impl<T: ?Sized> Drop for ProjectArc<T> {
 
 common_impls!(ProjectArc);
 
+/// A non-owning handle to a [`ProjectArc`] allocation.
+///
+/// A `WeakProjectArc` does not keep its pointee alive; it must be
+/// [`upgrade`](WeakProjectArc::upgrade)d into a `ProjectArc` before the
+/// pointee can be accessed.
+pub struct WeakProjectArc<T: ?Sized> {
+    inner: NonNull<ArcInner>,
+    pointer: NonNull<T>,
+}
+
+unsafe impl<T> Send for WeakProjectArc<T> where T: Send + Sync + ?Sized {}
+
+unsafe impl<T> Sync for WeakProjectArc<T> where T: Send + Sync + ?Sized {}
+
+impl<T: ?Sized> WeakProjectArc<T> {
+    fn inner(&self) -> &ArcInner {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// Attempts to upgrade this weak handle into a strong [`ProjectArc`],
+    /// returning `None` if the pointee has already been dropped.
+    pub fn upgrade(&self) -> Option<ProjectArc<T>> {
+        let mut strong = self.inner().strong.load(Ordering::Relaxed);
+
+        loop {
+            if strong == 0 {
+                return None;
+            }
+
+            match self.inner().strong.compare_exchange_weak(
+                strong,
+                strong + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    return Some(ProjectArc {
+                        inner: self.inner,
+                        pointer: self.pointer,
+                    })
+                }
+                Err(actual) => strong = actual,
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for WeakProjectArc<T> {
+    fn clone(&self) -> Self {
+        self.inner().weak.fetch_add(1, Ordering::Release);
+
+        WeakProjectArc {
+            inner: self.inner,
+            pointer: self.pointer,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for WeakProjectArc<T> {
+    fn drop(&mut self) {
+        let weak = self.inner().weak.fetch_sub(1, Ordering::AcqRel);
+
+        if weak == 1 {
+            unsafe {
+                deallocate(self.inner);
+            }
+        }
+    }
+}
+
+/// A borrowed, non-owning view of a [`ProjectArc`], obtained via
+/// [`ProjectArc::borrow`].
+///
+/// Unlike [`ProjectArc`] itself, `ProjectArcBorrow` never touches the strong
+/// count: it is a plain `Copy` wrapper tied to the lifetime of the handle it
+/// was borrowed from. Call [`upgrade`](ProjectArcBorrow::upgrade) when a
+/// caller genuinely needs to extend the pointee's lifetime past `'a`.
+pub struct ProjectArcBorrow<'a, T: ?Sized> {
+    inner: NonNull<ArcInner>,
+    pointer: NonNull<T>,
+    _marker: PhantomData<&'a T>,
+}
+
+unsafe impl<'a, T> Send for ProjectArcBorrow<'a, T> where T: Send + Sync + ?Sized {}
+
+unsafe impl<'a, T> Sync for ProjectArcBorrow<'a, T> where T: Send + Sync + ?Sized {}
+
+impl<'a, T: ?Sized> Clone for ProjectArcBorrow<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ?Sized> Copy for ProjectArcBorrow<'a, T> {}
+
+impl<'a, T: ?Sized> ProjectArcBorrow<'a, T> {
+    fn inner(&self) -> &ArcInner {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// Narrows this borrow to a sub-field, without ever bumping the strong
+    /// count.
+    pub fn project<F, U: ?Sized>(self, f: F) -> ProjectArcBorrow<'a, U>
+    where
+        F: for<'b> FnOnce(&'b T) -> &'b U,
+    {
+        let pointer = f(unsafe { self.pointer.as_ref() });
+
+        ProjectArcBorrow {
+            inner: self.inner,
+            pointer: pointer.into(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Equivalent to [`project`](ProjectArcBorrow::project), spelled to match
+    /// [`ProjectArc::clone_project`] since a `ProjectArcBorrow` is `Copy` and
+    /// doesn't need to distinguish "consume" from "clone then consume".
+    pub fn clone_project<F, U: ?Sized>(&self, f: F) -> ProjectArcBorrow<'a, U>
+    where
+        F: for<'b> FnOnce(&'b T) -> &'b U,
+    {
+        (*self).project(f)
+    }
+
+    /// Extends this borrow into an owning [`ProjectArc`], incrementing the
+    /// strong count exactly once.
+    pub fn upgrade(self) -> ProjectArc<T> {
+        self.inner().strong.fetch_add(1, Ordering::Release);
+
+        ProjectArc {
+            inner: self.inner,
+            pointer: self.pointer,
+        }
+    }
+}
+
+impl<'a, T: Deref + ?Sized> ProjectArcBorrow<'a, T> {
+    pub fn project_deref(self) -> ProjectArcBorrow<'a, T::Target> {
+        self.project(T::deref)
+    }
+}
+
+impl<'a, T: ?Sized> Deref for ProjectArcBorrow<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.pointer.as_ref() }
+    }
+}
+
 #[cfg(feature = "unsize")]
 mod unsize_impl {
     use std::marker::Unsize;
     use std::ops::CoerceUnsized;
 
-    impl<T, U> CoerceUnsized<ProjectRc<U>> for ProjectRc<T>
+    use super::ProjectArc;
+
+    impl<T, U> CoerceUnsized<ProjectArc<U>> for ProjectArc<T>
     where
         T: Unsize<U> + ?Sized,
         U: ?Sized,
@@ -130,11 +690,16 @@ mod unsize_impl {
 #[repr(C)]
 struct ArcInner {
     strong: AtomicUsize,
+    weak: AtomicUsize,
     drop: TypeMetadata,
     // payload: [u8],
 }
 
-unsafe fn deallocate(inner: NonNull<ArcInner>) {
+/// Drops the payload in place, without deallocating the backing allocation.
+///
+/// SAFETY: must only be called once, when the strong count has just dropped
+/// from 1 to 0.
+unsafe fn drop_payload(inner: NonNull<ArcInner>) {
     let meta = unsafe { (*inner.as_ptr()).drop };
     let layout = arc_inner_layout(meta);
 
@@ -143,6 +708,21 @@ unsafe fn deallocate(inner: NonNull<ArcInner>) {
 
     unsafe {
         drop_in_place(payload, meta);
+    }
+}
+
+/// Frees the backing allocation.
+///
+/// SAFETY: must only be called once, when the weak count has just dropped
+/// to 0, and after the payload (if ever initialized) has already been
+/// dropped.
+unsafe fn deallocate(inner: NonNull<ArcInner>) {
+    let meta = unsafe { (*inner.as_ptr()).drop };
+    let layout = arc_inner_layout(meta);
+
+    let inner_ptr = inner.as_ptr() as *mut u8;
+
+    unsafe {
         std::alloc::dealloc(inner_ptr, layout.layout);
     }
 }
@@ -150,12 +730,14 @@ unsafe fn deallocate(inner: NonNull<ArcInner>) {
 struct ArcInnerLayout {
     layout: Layout,
     strong_offset: usize,
+    weak_offset: usize,
     drop_offset: usize,
     payload_offset: usize,
 }
 
 fn arc_inner_layout(meta: TypeMetadata) -> ArcInnerLayout {
     let (layout, strong_offset) = (Layout::new::<AtomicUsize>(), 0);
+    let (layout, weak_offset) = layout.extend(Layout::new::<AtomicUsize>()).unwrap();
     let (layout, drop_offset) = layout.extend(Layout::new::<TypeMetadata>()).unwrap();
     let (layout, payload_offset) = layout
         .extend(Layout::from_size_align(meta.size_of(), meta.align_of()).unwrap())
@@ -165,6 +747,7 @@ fn arc_inner_layout(meta: TypeMetadata) -> ArcInnerLayout {
     ArcInnerLayout {
         layout,
         strong_offset,
+        weak_offset,
         drop_offset,
         payload_offset,
     }
@@ -227,6 +810,7 @@ mod test {
     }
 
     #[test]
+    #[cfg(feature = "unsize")]
     fn project_slice() {
         let p1: ProjectArc<[i32]> = ProjectArc::new([1, 2, 3]);
 
@@ -252,4 +836,282 @@ mod test {
 
         assert_eq!(&*p1, "Hell");
     }
+
+    #[test]
+    fn weak_upgrade() {
+        let dropped = &AtomicBool::new(false);
+
+        let p1 = ProjectArc::new(SideEffect(12345, |_| {
+            dropped.store(true, Ordering::SeqCst);
+        }));
+        let weak = p1.downgrade();
+
+        assert_eq!(p1.strong_count(), 1);
+        assert_eq!(p1.weak_count(), 1);
+
+        let p2 = weak.upgrade().unwrap();
+        assert_eq!((*p2).0, 12345);
+        assert_eq!(p1.strong_count(), 2);
+
+        drop(p1);
+        assert!(!dropped.load(Ordering::SeqCst));
+        drop(p2);
+        assert!(dropped.load(Ordering::SeqCst));
+
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn weak_does_not_keep_payload_alive() {
+        let dropped = &AtomicBool::new(false);
+
+        let p1 = ProjectArc::new(SideEffect(12345, |_| {
+            dropped.store(true, Ordering::SeqCst);
+        }));
+        let weak = p1.downgrade();
+
+        drop(p1);
+        assert!(dropped.load(Ordering::SeqCst));
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn get_mut_requires_unique_ownership() {
+        let mut p1 = ProjectArc::new(vec![1, 2, 3]);
+        p1.get_mut().unwrap().push(4);
+        assert_eq!(*p1, [1, 2, 3, 4]);
+
+        let p2 = p1.clone();
+        assert!(p1.get_mut().is_none());
+
+        drop(p2);
+        assert!(p1.get_mut().is_some());
+
+        let weak = p1.downgrade();
+        assert!(p1.get_mut().is_none());
+
+        drop(weak);
+        assert!(p1.get_mut().is_some());
+    }
+
+    #[test]
+    fn make_mut_clones_on_write() {
+        let mut p1 = ProjectArc::new(vec![1, 2, 3]);
+        let p2 = p1.clone();
+
+        p1.make_mut().push(4);
+
+        assert_eq!(*p1, [1, 2, 3, 4]);
+        assert_eq!(*p2, [1, 2, 3]);
+
+        // Now that p1 is unique again, make_mut shouldn't need to clone.
+        let ptr_before = p1.make_mut() as *mut Vec<i32>;
+        let ptr_after = p1.make_mut() as *mut Vec<i32>;
+        assert_eq!(ptr_before, ptr_after);
+    }
+
+    #[test]
+    fn from_slice() {
+        let p1 = ProjectArc::from_slice(&[1, 2, 3]);
+
+        assert_eq!(*p1, [1, 2, 3]);
+    }
+
+    struct PanicOnClone<'a> {
+        dropped: &'a AtomicUsize,
+        should_panic: bool,
+    }
+
+    impl Clone for PanicOnClone<'_> {
+        fn clone(&self) -> Self {
+            assert!(!self.should_panic, "clone panics");
+            PanicOnClone {
+                dropped: self.dropped,
+                should_panic: self.should_panic,
+            }
+        }
+    }
+
+    impl Drop for PanicOnClone<'_> {
+        fn drop(&mut self) {
+            self.dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn from_slice_drops_written_prefix_on_clone_panic() {
+        let dropped = &AtomicUsize::new(0);
+        let items = [
+            PanicOnClone { dropped, should_panic: false },
+            PanicOnClone { dropped, should_panic: false },
+            PanicOnClone { dropped, should_panic: true },
+        ];
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ProjectArc::from_slice(&items)
+        }));
+
+        assert!(result.is_err());
+        // The two elements already cloned into the new allocation before
+        // the panic must have been dropped by the unwind guard, not leaked.
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn collect_slice() {
+        let p1 = ProjectArc::collect_slice((1..=3).map(|n| n * 10));
+
+        assert_eq!(*p1, [10, 20, 30]);
+    }
+
+    #[test]
+    fn collect_slice_drops_every_element() {
+        let count = &AtomicUsize::new(0);
+
+        let items = (0..3).map(|_| SideEffect((), |_| {
+            count.fetch_add(1, Ordering::SeqCst);
+        }));
+        let p1 = ProjectArc::collect_slice(items);
+
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+
+        drop(p1);
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn copy_str() {
+        let p1 = ProjectArc::<str>::copy_str("Hello, world!");
+
+        assert_eq!(&*p1, "Hello, world!");
+    }
+
+    #[test]
+    fn borrow_does_not_touch_strong_count() {
+        let p1 = ProjectArc::new(vec![1, 2, 3]);
+        let b1 = p1.borrow();
+
+        assert_eq!(*b1, [1, 2, 3]);
+        assert_eq!(p1.strong_count(), 1);
+
+        let b2 = b1;
+        assert_eq!(*b2, [1, 2, 3]);
+        assert_eq!(p1.strong_count(), 1);
+    }
+
+    #[test]
+    fn borrow_project_and_upgrade() {
+        let p1 = ProjectArc::new(vec![1, 2, 3]);
+        let b1 = p1.borrow().project(|v| &v[1]);
+
+        assert_eq!(*b1, 2);
+
+        let p2 = b1.upgrade();
+        assert_eq!(*p2, 2);
+        assert_eq!(p1.strong_count(), 2);
+    }
+
+    #[test]
+    fn borrow_project_deref() {
+        let p1: ProjectArc<Vec<i32>> = ProjectArc::new(vec![1, 2, 3]);
+        let b1 = p1.borrow().project_deref();
+
+        assert_eq!(&*b1, [1, 2, 3]);
+    }
+
+    struct Node {
+        value: i32,
+        me: WeakProjectArc<Node>,
+    }
+
+    #[test]
+    fn new_cyclic_self_reference() {
+        let node = ProjectArc::new_cyclic(|me| Node {
+            value: 12345,
+            me: me.clone(),
+        });
+
+        assert_eq!(node.value, 12345);
+        assert_eq!(node.strong_count(), 1);
+        assert_eq!(node.weak_count(), 1);
+
+        let upgraded = node.me.upgrade().unwrap();
+        assert_eq!(upgraded.value, 12345);
+        assert_eq!(node.strong_count(), 2);
+    }
+
+    #[test]
+    fn new_cyclic_weak_is_unusable_during_construction() {
+        ProjectArc::new_cyclic(|me| {
+            assert!(me.upgrade().is_none());
+            12345
+        });
+    }
+
+    #[test]
+    fn new_cyclic_deallocates_without_dropping_payload_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            ProjectArc::<SideEffect<i32, fn(&mut i32)>>::new_cyclic(|_| panic!("nope"));
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn into_raw_from_raw_round_trip() {
+        let dropped = &AtomicBool::new(false);
+
+        let p1 = ProjectArc::new(SideEffect(12345, |_| {
+            dropped.store(true, Ordering::SeqCst);
+        }));
+
+        let ptr = ProjectArc::into_raw(p1);
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        let p2 = unsafe { ProjectArc::from_raw(ptr) };
+        assert_eq!((*p2).0, 12345);
+
+        drop(p2);
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn increment_and_decrement_strong_count() {
+        let p1 = ProjectArc::new(vec![1, 2, 3]);
+        let ptr = ProjectArc::into_raw(p1.clone());
+
+        assert_eq!(p1.strong_count(), 2);
+
+        unsafe {
+            ProjectArc::increment_strong_count(ptr);
+        }
+        assert_eq!(p1.strong_count(), 3);
+
+        unsafe {
+            ProjectArc::decrement_strong_count(ptr);
+        }
+        assert_eq!(p1.strong_count(), 2);
+
+        unsafe {
+            ProjectArc::decrement_strong_count(ptr);
+        }
+        assert_eq!(p1.strong_count(), 1);
+    }
+
+    #[test]
+    fn unsize_array() {
+        let p1: ProjectArc<[i32; 3]> = ProjectArc::new([1, 2, 3]);
+        let p2: ProjectArc<[i32]> = p1.unsize_array();
+
+        assert_eq!(*p2, [1, 2, 3]);
+    }
+
+    #[test]
+    fn project_unsize_to_trait_object() {
+        let p1: ProjectArc<i32> = ProjectArc::new(12345);
+        let p2: ProjectArc<dyn std::fmt::Display> =
+            p1.project_unsize(|n| n as &dyn std::fmt::Display);
+
+        assert_eq!(format!("{}", &*p2), "12345");
+    }
 }
@@ -1,38 +1,57 @@
 use std::{
     alloc::{alloc, handle_alloc_error, Layout},
     cell::Cell,
+    marker::PhantomData,
     mem::ManuallyDrop,
     ops::Deref,
     ptr::{null_mut, NonNull},
 };
 
-use crate::metadata::{drop_in_place, metadata_of, TypeMetadata};
+use crate::metadata::{drop_in_place, metadata_of, metadata_of_slice, TypeMetadata};
 
 pub struct ProjectRc<T: ?Sized> {
     inner: NonNull<RcInner>,
     pointer: NonNull<T>,
 }
 
+/// Allocates an `RcInner` for `meta`, with the strong/weak counts and
+/// metadata already written, leaving only the payload to be initialized.
+///
+/// `initial_strong` is almost always `1`; [`ProjectRc::new_cyclic`] is the
+/// one exception, starting at `0` so that weak upgrades during construction
+/// correctly observe no live strong handles yet.
+fn alloc_inner(meta: TypeMetadata, initial_strong: usize) -> (*mut u8, RcInnerLayout) {
+    let layout = rc_inner_layout(meta);
+
+    let ptr = unsafe { alloc(layout.layout) };
+
+    if ptr == null_mut() {
+        handle_alloc_error(layout.layout);
+    }
+
+    unsafe {
+        // Write the strong count
+        ptr.add(layout.strong_offset)
+            .cast::<Cell<usize>>()
+            .write(Cell::new(initial_strong));
+        // Write 1 as the weak count: all outstanding strong handles
+        // collectively own a single unit of weak count.
+        ptr.add(layout.weak_offset)
+            .cast::<Cell<usize>>()
+            .write(Cell::new(1));
+        // Write the metadata
+        ptr.add(layout.drop_offset).cast::<TypeMetadata>().write(meta);
+    }
+
+    (ptr, layout)
+}
+
 impl<T> ProjectRc<T> {
     pub fn new(thing: T) -> Self {
         let meta = metadata_of::<T>();
-        let layout = rc_inner_layout(meta);
-
-        let ptr = unsafe { alloc(layout.layout) };
-
-        if ptr == null_mut() {
-            handle_alloc_error(layout.layout);
-        }
+        let (ptr, layout) = alloc_inner(meta, 1);
 
         unsafe {
-            // Write 0 as the strong count
-            ptr.add(layout.strong_offset)
-                .cast::<Cell<usize>>()
-                .write(Cell::new(1));
-            // Write the metadata
-            ptr.add(layout.drop_offset)
-                .cast::<TypeMetadata>()
-                .write(meta);
             // Write the actual pointee
             ptr.add(layout.payload_offset).cast::<T>().write(thing);
 
@@ -45,6 +64,242 @@ impl<T> ProjectRc<T> {
             }
         }
     }
+
+    /// Constructs a `ProjectRc<T>` that can hold a weak pointer back to
+    /// itself, by giving `data_fn` a [`WeakProjectRc<T>`] aimed at the
+    /// (not yet initialized) allocation.
+    ///
+    /// The strong count stays at `0` for the duration of `data_fn`, so any
+    /// `upgrade` attempted on the passed-in weak handle before construction
+    /// finishes correctly observes no live owner and returns `None`. If
+    /// `data_fn` panics, the allocation is freed without ever dropping the
+    /// payload, since it was never initialized.
+    pub fn new_cyclic<F>(data_fn: F) -> Self
+    where
+        F: FnOnce(&WeakProjectRc<T>) -> T,
+    {
+        let meta = metadata_of::<T>();
+        let (ptr, layout) = alloc_inner(meta, 0);
+
+        let inner_ptr = NonNull::new(ptr as *mut RcInner).unwrap();
+        let payload_ptr =
+            NonNull::new(unsafe { ptr.add(layout.payload_offset) } as *mut T).unwrap();
+
+        // This owns the one unit of weak count we just allocated. If
+        // `data_fn` panics, unwinding drops it here, which frees the
+        // allocation without touching the uninitialized payload.
+        let weak = WeakProjectRc {
+            inner: inner_ptr,
+            pointer: payload_ptr,
+        };
+
+        let value = data_fn(&weak);
+
+        unsafe {
+            payload_ptr.as_ptr().write(value);
+
+            // Hand the weak handle's unit of weak count off to the strong
+            // handles we're about to create, instead of giving it back by
+            // dropping `weak` normally.
+            std::mem::forget(weak);
+            (*inner_ptr.as_ptr()).strong.set(1);
+        }
+
+        ProjectRc {
+            inner: inner_ptr,
+            pointer: payload_ptr,
+        }
+    }
+
+    /// Consumes the handle and returns a raw pointer to the payload,
+    /// without decrementing the strong count.
+    ///
+    /// The returned pointer carries the strong count this handle held, which
+    /// must eventually be released by passing it to
+    /// [`from_raw`](ProjectRc::from_raw) (and dropping the result) or to
+    /// [`decrement_strong_count`](ProjectRc::decrement_strong_count), or the
+    /// allocation leaks. Each call to
+    /// [`increment_strong_count`](ProjectRc::increment_strong_count) on it
+    /// adds one more strong count to be released the same way. All three
+    /// recover the header by walking backwards
+    /// from the payload by the same offset `new` used to place it, so `ptr`
+    /// must still point at the start of the live payload: a handle that has
+    /// been [`project`](ProjectRc::project)ed onto a sub-field no longer
+    /// satisfies that and must not be passed through `into_raw`.
+    pub fn into_raw(self) -> *const T {
+        let self_ = ManuallyDrop::new(self);
+        self_.pointer.as_ptr()
+    }
+
+    /// Reconstructs a `ProjectRc<T>` from a pointer previously returned by
+    /// [`ProjectRc::into_raw`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have come from `into_raw` called on a `ProjectRc<T>` of
+    /// this same, unprojected `T`, and must still carry a strong count that
+    /// has not been released yet: `into_raw` and each `increment_strong_count`
+    /// give it one, `from_raw` and each `decrement_strong_count` release one.
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        let layout = rc_inner_layout(metadata_of::<T>());
+        let inner = unsafe { (ptr as *mut u8).sub(layout.payload_offset) } as *mut RcInner;
+
+        ProjectRc {
+            inner: NonNull::new(inner).unwrap(),
+            pointer: NonNull::new(ptr as *mut T).unwrap(),
+        }
+    }
+
+    /// Increments the strong count of the allocation `ptr` points into,
+    /// without materializing a `ProjectRc<T>`.
+    ///
+    /// # Safety
+    ///
+    /// Same pairing contract as [`ProjectRc::from_raw`]: `ptr` must have
+    /// come from `into_raw` of an unprojected `ProjectRc<T>`, and the
+    /// allocation must still be live.
+    pub unsafe fn increment_strong_count(ptr: *const T) {
+        let rc = ManuallyDrop::new(unsafe { ProjectRc::from_raw(ptr) });
+        // Cloning bumps the strong count; forgetting the clone (and never
+        // dropping `rc` itself) leaves `ptr` accounting for exactly the one
+        // unit of ownership it already represented, plus the new one just
+        // created.
+        std::mem::forget((*rc).clone());
+    }
+
+    /// Decrements the strong count of the allocation `ptr` points into,
+    /// dropping the payload and/or deallocating if this was the last
+    /// strong (or weak) handle.
+    ///
+    /// # Safety
+    ///
+    /// Same pairing contract as [`ProjectRc::from_raw`]: `ptr` must have
+    /// come from `into_raw` of an unprojected `ProjectRc<T>`, and must still
+    /// carry a strong count that has not been released yet.
+    pub unsafe fn decrement_strong_count(ptr: *const T) {
+        drop(unsafe { ProjectRc::from_raw(ptr) });
+    }
+}
+
+impl<T: Clone> ProjectRc<[T]> {
+    /// Builds a `ProjectRc<[T]>` by cloning every element out of `slice`
+    /// into a single allocation holding the header and the payload inline.
+    pub fn from_slice(slice: &[T]) -> Self {
+        let len = slice.len();
+        let meta = metadata_of_slice::<T>(len);
+        let (ptr, layout) = alloc_inner(meta, 1);
+
+        unsafe {
+            let payload_base = ptr.add(layout.payload_offset) as *mut T;
+
+            // `item.clone()` can panic partway through; this guard drops
+            // the elements already written and frees the allocation on
+            // unwind, the same way std builds `Rc<[T]>`/`Arc<[T]>` from a
+            // slice.
+            let mut guard = PartialSliceWrite {
+                ptr,
+                layout: layout.layout,
+                payload_base,
+                written: 0,
+            };
+            for item in slice {
+                payload_base.add(guard.written).write(item.clone());
+                guard.written += 1;
+            }
+            std::mem::forget(guard);
+
+            let inner_ptr = NonNull::new(ptr as *mut RcInner).unwrap();
+            let pointer =
+                NonNull::new(std::ptr::slice_from_raw_parts_mut(payload_base, len)).unwrap();
+
+            ProjectRc {
+                inner: inner_ptr,
+                pointer,
+            }
+        }
+    }
+}
+
+/// Drops the already-written prefix of a partially-initialized slice
+/// payload and frees the backing allocation, for unwinding out of a
+/// panicking per-element write (e.g. [`ProjectRc::from_slice`]'s
+/// `item.clone()`).
+struct PartialSliceWrite<T> {
+    ptr: *mut u8,
+    layout: Layout,
+    payload_base: *mut T,
+    written: usize,
+}
+
+impl<T> Drop for PartialSliceWrite<T> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in 0..self.written {
+                self.payload_base.add(i).drop_in_place();
+            }
+            std::alloc::dealloc(self.ptr, self.layout);
+        }
+    }
+}
+
+impl<T> ProjectRc<[T]> {
+    /// Builds a `ProjectRc<[T]>` from an iterator, without needing to know
+    /// the length up front.
+    ///
+    /// Named `collect_slice` rather than `from_iter` so it doesn't collide
+    /// with (and shadow) `std::iter::FromIterator::from_iter`.
+    pub fn collect_slice<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        let len = items.len();
+        let meta = metadata_of_slice::<T>(len);
+        let (ptr, layout) = alloc_inner(meta, 1);
+
+        unsafe {
+            let payload_base = ptr.add(layout.payload_offset) as *mut T;
+
+            for (i, item) in items.into_iter().enumerate() {
+                payload_base.add(i).write(item);
+            }
+
+            let inner_ptr = NonNull::new(ptr as *mut RcInner).unwrap();
+            let pointer =
+                NonNull::new(std::ptr::slice_from_raw_parts_mut(payload_base, len)).unwrap();
+
+            ProjectRc {
+                inner: inner_ptr,
+                pointer,
+            }
+        }
+    }
+}
+
+impl ProjectRc<str> {
+    /// Builds a `ProjectRc<str>` by copying `s`'s bytes into a single
+    /// allocation holding the header and the payload inline.
+    ///
+    /// Named `copy_str` rather than `from_str` so it doesn't collide with
+    /// (and shadow) `std::str::FromStr::from_str`.
+    pub fn copy_str(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let len = bytes.len();
+        let meta = metadata_of_slice::<u8>(len);
+        let (ptr, layout) = alloc_inner(meta, 1);
+
+        unsafe {
+            let payload_base = ptr.add(layout.payload_offset);
+            payload_base.copy_from_nonoverlapping(bytes.as_ptr(), len);
+
+            let inner_ptr = NonNull::new(ptr as *mut RcInner).unwrap();
+            // `bytes` came from a valid `&str`, so the copy is valid UTF-8.
+            let byte_slice = std::slice::from_raw_parts_mut(payload_base, len);
+            let pointer = NonNull::from(std::str::from_utf8_unchecked_mut(byte_slice));
+
+            ProjectRc {
+                inner: inner_ptr,
+                pointer,
+            }
+        }
+    }
 }
 
 impl<T: ?Sized> ProjectRc<T> {
@@ -75,6 +330,100 @@ impl<T: ?Sized> ProjectRc<T> {
     {
         self.clone().project(f)
     }
+
+    /// Coerces the projected value into an unsized `U` (for example,
+    /// `dyn Trait`), on stable.
+    ///
+    /// This is just [`project`](ProjectRc::project) under a name that makes
+    /// the "turn this concrete type into a trait object" case
+    /// discoverable without the nightly `unsize` feature: the ordinary
+    /// reference-unsizing coercion (`&T` to `&dyn Trait`) is stable, so `f`
+    /// can build `&U` by simply returning its argument under an explicit
+    /// unsized return type.
+    pub fn project_unsize<F, U: ?Sized>(self, f: F) -> ProjectRc<U>
+    where
+        F: for<'a> FnOnce(&'a T) -> &'a U,
+    {
+        self.project(f)
+    }
+}
+
+impl<T, const N: usize> ProjectRc<[T; N]> {
+    /// Converts a `ProjectRc` over a fixed-size array into one over a
+    /// slice, on stable, by projecting onto the array's `as_slice()`.
+    pub fn unsize_array(self) -> ProjectRc<[T]> {
+        self.project(|array| array.as_slice())
+    }
+}
+
+impl<T: ?Sized> ProjectRc<T> {
+    /// Returns the number of strong (`ProjectRc`) handles to this allocation.
+    pub fn strong_count(&self) -> usize {
+        self.inner().strong.get()
+    }
+
+    /// Returns the number of [`WeakProjectRc`] handles to this allocation.
+    pub fn weak_count(&self) -> usize {
+        // One unit of weak count is owned collectively by the strong
+        // handles, so it isn't counted here.
+        self.inner().weak.get() - 1
+    }
+
+    /// Creates a new [`WeakProjectRc`] pointer to this allocation.
+    pub fn downgrade(&self) -> WeakProjectRc<T> {
+        let weak = self.inner().weak.get();
+        self.inner().weak.set(weak + 1);
+
+        WeakProjectRc {
+            inner: self.inner,
+            pointer: self.pointer,
+        }
+    }
+
+    /// Returns true if this is the only strong handle and there are no
+    /// outstanding [`WeakProjectRc`] handles either.
+    fn is_unique(&self) -> bool {
+        self.inner().strong.get() == 1 && self.inner().weak.get() == 1
+    }
+
+    /// Returns a mutable reference to the projected value, but only if this
+    /// is the only handle (strong or weak) to the allocation.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.is_unique() {
+            Some(unsafe { &mut *self.pointer.as_ptr() })
+        } else {
+            None
+        }
+    }
+
+    /// Borrows this handle without touching the strong count.
+    ///
+    /// The resulting [`ProjectRcBorrow`] is a plain `Copy` value tied to the
+    /// lifetime of `self`, useful for passing through call chains that only
+    /// need read access without paying for a clone/drop pair at every step.
+    pub fn borrow(&self) -> ProjectRcBorrow<'_, T> {
+        ProjectRcBorrow {
+            inner: self.inner,
+            pointer: self.pointer,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> ProjectRc<T> {
+    /// Returns a mutable reference to the value, cloning the allocation
+    /// first if this handle is not the sole owner.
+    ///
+    /// Only the value this handle points at is cloned: on a handle projected
+    /// down to a sub-field, the new allocation holds just that sub-field,
+    /// not the full original value.
+    pub fn make_mut(&mut self) -> &mut T {
+        if !self.is_unique() {
+            *self = ProjectRc::new((**self).clone());
+        }
+
+        unsafe { &mut *self.pointer.as_ptr() }
+    }
 }
 
 impl<T: ?Sized> Deref for ProjectRc<T> {
@@ -104,7 +453,18 @@ impl<T: ?Sized> Drop for ProjectRc<T> {
 
         if strong == 1 {
             unsafe {
-                deallocate(self.inner);
+                drop_payload(self.inner);
+            }
+
+            // The strong handles no longer exist, so they give up their
+            // shared unit of weak count.
+            let weak = self.inner().weak.get();
+            self.inner().weak.set(weak - 1);
+
+            if weak == 1 {
+                unsafe {
+                    deallocate(self.inner);
+                }
             }
         }
     }
@@ -112,11 +472,149 @@ impl<T: ?Sized> Drop for ProjectRc<T> {
 
 common_impls!(ProjectRc);
 
+/// A non-owning handle to a [`ProjectRc`] allocation.
+///
+/// A `WeakProjectRc` does not keep its pointee alive; it must be
+/// [`upgrade`](WeakProjectRc::upgrade)d into a `ProjectRc` before the
+/// pointee can be accessed.
+pub struct WeakProjectRc<T: ?Sized> {
+    inner: NonNull<RcInner>,
+    pointer: NonNull<T>,
+}
+
+impl<T: ?Sized> WeakProjectRc<T> {
+    fn inner(&self) -> &RcInner {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// Attempts to upgrade this weak handle into a strong [`ProjectRc`],
+    /// returning `None` if the pointee has already been dropped.
+    pub fn upgrade(&self) -> Option<ProjectRc<T>> {
+        let strong = self.inner().strong.get();
+
+        if strong == 0 {
+            return None;
+        }
+
+        self.inner().strong.set(strong + 1);
+
+        Some(ProjectRc {
+            inner: self.inner,
+            pointer: self.pointer,
+        })
+    }
+}
+
+impl<T: ?Sized> Clone for WeakProjectRc<T> {
+    fn clone(&self) -> Self {
+        let weak = self.inner().weak.get();
+        self.inner().weak.set(weak + 1);
+
+        WeakProjectRc {
+            inner: self.inner,
+            pointer: self.pointer,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for WeakProjectRc<T> {
+    fn drop(&mut self) {
+        let weak = self.inner().weak.get();
+        self.inner().weak.set(weak - 1);
+
+        if weak == 1 {
+            unsafe {
+                deallocate(self.inner);
+            }
+        }
+    }
+}
+
+/// A borrowed, non-owning view of a [`ProjectRc`], obtained via
+/// [`ProjectRc::borrow`].
+///
+/// Unlike [`ProjectRc`] itself, `ProjectRcBorrow` never touches the strong
+/// count: it is a plain `Copy` wrapper tied to the lifetime of the handle it
+/// was borrowed from. Call [`upgrade`](ProjectRcBorrow::upgrade) when a
+/// caller genuinely needs to extend the pointee's lifetime past `'a`.
+pub struct ProjectRcBorrow<'a, T: ?Sized> {
+    inner: NonNull<RcInner>,
+    pointer: NonNull<T>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: ?Sized> Clone for ProjectRcBorrow<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ?Sized> Copy for ProjectRcBorrow<'a, T> {}
+
+impl<'a, T: ?Sized> ProjectRcBorrow<'a, T> {
+    fn inner(&self) -> &RcInner {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// Narrows this borrow to a sub-field, without ever bumping the strong
+    /// count.
+    pub fn project<F, U: ?Sized>(self, f: F) -> ProjectRcBorrow<'a, U>
+    where
+        F: for<'b> FnOnce(&'b T) -> &'b U,
+    {
+        let pointer = f(unsafe { self.pointer.as_ref() });
+
+        ProjectRcBorrow {
+            inner: self.inner,
+            pointer: pointer.into(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Equivalent to [`project`](ProjectRcBorrow::project), spelled to match
+    /// [`ProjectRc::clone_project`] since a `ProjectRcBorrow` is `Copy` and
+    /// doesn't need to distinguish "consume" from "clone then consume".
+    pub fn clone_project<F, U: ?Sized>(&self, f: F) -> ProjectRcBorrow<'a, U>
+    where
+        F: for<'b> FnOnce(&'b T) -> &'b U,
+    {
+        (*self).project(f)
+    }
+
+    /// Extends this borrow into an owning [`ProjectRc`], incrementing the
+    /// strong count exactly once.
+    pub fn upgrade(self) -> ProjectRc<T> {
+        let strong = self.inner().strong.get();
+        self.inner().strong.set(strong + 1);
+
+        ProjectRc {
+            inner: self.inner,
+            pointer: self.pointer,
+        }
+    }
+}
+
+impl<'a, T: Deref + ?Sized> ProjectRcBorrow<'a, T> {
+    pub fn project_deref(self) -> ProjectRcBorrow<'a, T::Target> {
+        self.project(T::deref)
+    }
+}
+
+impl<'a, T: ?Sized> Deref for ProjectRcBorrow<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.pointer.as_ref() }
+    }
+}
+
 #[cfg(feature = "unsize")]
 mod unsize_impl {
     use std::marker::Unsize;
     use std::ops::CoerceUnsized;
 
+    use super::ProjectRc;
+
     impl<T, U> CoerceUnsized<ProjectRc<U>> for ProjectRc<T>
     where
         T: Unsize<U> + ?Sized,
@@ -128,11 +626,16 @@ mod unsize_impl {
 #[repr(C)]
 struct RcInner {
     strong: Cell<usize>,
+    weak: Cell<usize>,
     drop: TypeMetadata,
     // payload: [u8],
 }
 
-unsafe fn deallocate(inner: NonNull<RcInner>) {
+/// Drops the payload in place, without deallocating the backing allocation.
+///
+/// SAFETY: must only be called once, when the strong count has just dropped
+/// from 1 to 0.
+unsafe fn drop_payload(inner: NonNull<RcInner>) {
     let meta = unsafe { (*inner.as_ptr()).drop };
     let layout = rc_inner_layout(meta);
 
@@ -141,6 +644,21 @@ unsafe fn deallocate(inner: NonNull<RcInner>) {
 
     unsafe {
         drop_in_place(payload, meta);
+    }
+}
+
+/// Frees the backing allocation.
+///
+/// SAFETY: must only be called once, when the weak count has just dropped
+/// to 0, and after the payload (if ever initialized) has already been
+/// dropped.
+unsafe fn deallocate(inner: NonNull<RcInner>) {
+    let meta = unsafe { (*inner.as_ptr()).drop };
+    let layout = rc_inner_layout(meta);
+
+    let inner_ptr = inner.as_ptr() as *mut u8;
+
+    unsafe {
         std::alloc::dealloc(inner_ptr, layout.layout);
     }
 }
@@ -148,12 +666,14 @@ unsafe fn deallocate(inner: NonNull<RcInner>) {
 struct RcInnerLayout {
     layout: Layout,
     strong_offset: usize,
+    weak_offset: usize,
     drop_offset: usize,
     payload_offset: usize,
 }
 
 fn rc_inner_layout(meta: TypeMetadata) -> RcInnerLayout {
     let (layout, strong_offset) = (Layout::new::<Cell<usize>>(), 0);
+    let (layout, weak_offset) = layout.extend(Layout::new::<Cell<usize>>()).unwrap();
     let (layout, drop_offset) = layout.extend(Layout::new::<TypeMetadata>()).unwrap();
     let (layout, payload_offset) = layout
         .extend(Layout::from_size_align(meta.size_of(), meta.align_of()).unwrap())
@@ -163,6 +683,7 @@ fn rc_inner_layout(meta: TypeMetadata) -> RcInnerLayout {
     RcInnerLayout {
         layout,
         strong_offset,
+        weak_offset,
         drop_offset,
         payload_offset,
     }
@@ -223,6 +744,7 @@ mod test {
     }
 
     #[test]
+    #[cfg(feature = "unsize")]
     fn project_slice() {
         let p1: ProjectRc<[i32]> = ProjectRc::new([1, 2, 3]);
 
@@ -248,4 +770,279 @@ mod test {
 
         assert_eq!(&*p1, "Hell");
     }
+
+    #[test]
+    fn weak_upgrade() {
+        let dropped = &Cell::new(false);
+
+        let p1 = ProjectRc::new(SideEffect(12345, |_| {
+            dropped.set(true);
+        }));
+        let weak = p1.downgrade();
+
+        assert_eq!(p1.strong_count(), 1);
+        assert_eq!(p1.weak_count(), 1);
+
+        let p2 = weak.upgrade().unwrap();
+        assert_eq!((*p2).0, 12345);
+        assert_eq!(p1.strong_count(), 2);
+
+        drop(p1);
+        assert!(!dropped.get());
+        drop(p2);
+        assert!(dropped.get());
+
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn weak_does_not_keep_payload_alive() {
+        let dropped = &Cell::new(false);
+
+        let p1 = ProjectRc::new(SideEffect(12345, |_| {
+            dropped.set(true);
+        }));
+        let weak = p1.downgrade();
+
+        drop(p1);
+        assert!(dropped.get());
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn get_mut_requires_unique_ownership() {
+        let mut p1 = ProjectRc::new(vec![1, 2, 3]);
+        p1.get_mut().unwrap().push(4);
+        assert_eq!(*p1, [1, 2, 3, 4]);
+
+        let p2 = p1.clone();
+        assert!(p1.get_mut().is_none());
+
+        drop(p2);
+        assert!(p1.get_mut().is_some());
+
+        let weak = p1.downgrade();
+        assert!(p1.get_mut().is_none());
+
+        drop(weak);
+        assert!(p1.get_mut().is_some());
+    }
+
+    #[test]
+    fn make_mut_clones_on_write() {
+        let mut p1 = ProjectRc::new(vec![1, 2, 3]);
+        let p2 = p1.clone();
+
+        p1.make_mut().push(4);
+
+        assert_eq!(*p1, [1, 2, 3, 4]);
+        assert_eq!(*p2, [1, 2, 3]);
+
+        // Now that p1 is unique again, make_mut shouldn't need to clone.
+        let ptr_before = p1.make_mut() as *mut Vec<i32>;
+        let ptr_after = p1.make_mut() as *mut Vec<i32>;
+        assert_eq!(ptr_before, ptr_after);
+    }
+
+    #[test]
+    fn from_slice() {
+        let p1 = ProjectRc::from_slice(&[1, 2, 3]);
+
+        assert_eq!(*p1, [1, 2, 3]);
+    }
+
+    struct PanicOnClone<'a> {
+        dropped: &'a Cell<usize>,
+        should_panic: bool,
+    }
+
+    impl Clone for PanicOnClone<'_> {
+        fn clone(&self) -> Self {
+            assert!(!self.should_panic, "clone panics");
+            PanicOnClone {
+                dropped: self.dropped,
+                should_panic: self.should_panic,
+            }
+        }
+    }
+
+    impl Drop for PanicOnClone<'_> {
+        fn drop(&mut self) {
+            self.dropped.set(self.dropped.get() + 1);
+        }
+    }
+
+    #[test]
+    fn from_slice_drops_written_prefix_on_clone_panic() {
+        let dropped = &Cell::new(0);
+        let items = [
+            PanicOnClone { dropped, should_panic: false },
+            PanicOnClone { dropped, should_panic: false },
+            PanicOnClone { dropped, should_panic: true },
+        ];
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ProjectRc::from_slice(&items)
+        }));
+
+        assert!(result.is_err());
+        // The two elements already cloned into the new allocation before
+        // the panic must have been dropped by the unwind guard, not leaked.
+        assert_eq!(dropped.get(), 2);
+    }
+
+    #[test]
+    fn collect_slice() {
+        let p1 = ProjectRc::collect_slice((1..=3).map(|n| n * 10));
+
+        assert_eq!(*p1, [10, 20, 30]);
+    }
+
+    #[test]
+    fn collect_slice_drops_every_element() {
+        let count = &Cell::new(0);
+
+        let items = (0..3).map(|_| SideEffect((), |_| count.set(count.get() + 1)));
+        let p1 = ProjectRc::collect_slice(items);
+
+        assert_eq!(count.get(), 0);
+
+        drop(p1);
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn copy_str() {
+        let p1 = ProjectRc::<str>::copy_str("Hello, world!");
+
+        assert_eq!(&*p1, "Hello, world!");
+    }
+
+    #[test]
+    fn borrow_does_not_touch_strong_count() {
+        let p1 = ProjectRc::new(vec![1, 2, 3]);
+        let b1 = p1.borrow();
+
+        assert_eq!(*b1, [1, 2, 3]);
+        assert_eq!(p1.strong_count(), 1);
+
+        let b2 = b1;
+        assert_eq!(*b2, [1, 2, 3]);
+        assert_eq!(p1.strong_count(), 1);
+    }
+
+    #[test]
+    fn borrow_project_and_upgrade() {
+        let p1 = ProjectRc::new(vec![1, 2, 3]);
+        let b1 = p1.borrow().project(|v| &v[1]);
+
+        assert_eq!(*b1, 2);
+
+        let p2 = b1.upgrade();
+        assert_eq!(*p2, 2);
+        assert_eq!(p1.strong_count(), 2);
+    }
+
+    #[test]
+    fn borrow_project_deref() {
+        let p1: ProjectRc<Vec<i32>> = ProjectRc::new(vec![1, 2, 3]);
+        let b1 = p1.borrow().project_deref();
+
+        assert_eq!(&*b1, [1, 2, 3]);
+    }
+
+    struct Node {
+        value: i32,
+        me: WeakProjectRc<Node>,
+    }
+
+    #[test]
+    fn new_cyclic_self_reference() {
+        let node = ProjectRc::new_cyclic(|me| Node {
+            value: 12345,
+            me: me.clone(),
+        });
+
+        assert_eq!(node.value, 12345);
+        assert_eq!(node.strong_count(), 1);
+        assert_eq!(node.weak_count(), 1);
+
+        let upgraded = node.me.upgrade().unwrap();
+        assert_eq!(upgraded.value, 12345);
+        assert_eq!(node.strong_count(), 2);
+    }
+
+    #[test]
+    fn new_cyclic_weak_is_unusable_during_construction() {
+        ProjectRc::new_cyclic(|me| {
+            assert!(me.upgrade().is_none());
+            12345
+        });
+    }
+
+    #[test]
+    fn new_cyclic_deallocates_without_dropping_payload_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            ProjectRc::<SideEffect<i32, fn(&mut i32)>>::new_cyclic(|_| panic!("nope"));
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn into_raw_from_raw_round_trip() {
+        let dropped = &Cell::new(false);
+
+        let p1 = ProjectRc::new(SideEffect(12345, |_| {
+            dropped.set(true);
+        }));
+
+        let ptr = ProjectRc::into_raw(p1);
+        assert!(!dropped.get());
+
+        let p2 = unsafe { ProjectRc::from_raw(ptr) };
+        assert_eq!((*p2).0, 12345);
+
+        drop(p2);
+        assert!(dropped.get());
+    }
+
+    #[test]
+    fn increment_and_decrement_strong_count() {
+        let p1 = ProjectRc::new(vec![1, 2, 3]);
+        let ptr = ProjectRc::into_raw(p1.clone());
+
+        assert_eq!(p1.strong_count(), 2);
+
+        unsafe {
+            ProjectRc::increment_strong_count(ptr);
+        }
+        assert_eq!(p1.strong_count(), 3);
+
+        unsafe {
+            ProjectRc::decrement_strong_count(ptr);
+        }
+        assert_eq!(p1.strong_count(), 2);
+
+        unsafe {
+            ProjectRc::decrement_strong_count(ptr);
+        }
+        assert_eq!(p1.strong_count(), 1);
+    }
+
+    #[test]
+    fn unsize_array() {
+        let p1: ProjectRc<[i32; 3]> = ProjectRc::new([1, 2, 3]);
+        let p2: ProjectRc<[i32]> = p1.unsize_array();
+
+        assert_eq!(*p2, [1, 2, 3]);
+    }
+
+    #[test]
+    fn project_unsize_to_trait_object() {
+        let p1: ProjectRc<i32> = ProjectRc::new(12345);
+        let p2: ProjectRc<dyn std::fmt::Display> = p1.project_unsize(|n| n as &dyn std::fmt::Display);
+
+        assert_eq!(format!("{}", &*p2), "12345");
+    }
 }